@@ -0,0 +1,145 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    circuit::{AssignedCell, Chip, Layouter, Region},
+    pasta::group::ff::{Field, PrimeFieldBits},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+/// Given two assigned cells `x`, `y` and a boolean `swap`, conditionally
+/// swaps them: `a = if swap {y} else {x}`, `b = if swap {x} else {y}`.
+#[derive(Clone, Debug)]
+pub struct CondSwapConfig {
+    pub s_swap: Selector,
+    pub x: Column<Advice>,
+    pub y: Column<Advice>,
+    pub swap: Column<Advice>,
+    pub a: Column<Advice>,
+    pub b: Column<Advice>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CondSwapChip<F: PrimeFieldBits> {
+    config: CondSwapConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeFieldBits> Chip<F> for CondSwapChip<F> {
+    type Config = CondSwapConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: PrimeFieldBits> CondSwapChip<F> {
+    pub fn construct(config: CondSwapConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        x: Column<Advice>,
+        y: Column<Advice>,
+        swap: Column<Advice>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+    ) -> CondSwapConfig {
+        let s_swap = meta.selector();
+
+        meta.enable_equality(x);
+        meta.enable_equality(y);
+        meta.enable_equality(swap);
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        let config = CondSwapConfig {
+            s_swap,
+            x,
+            y,
+            swap,
+            a,
+            b,
+        };
+
+        meta.create_gate("conditional swap", |meta| {
+            let s_swap = meta.query_selector(config.s_swap);
+            let x = meta.query_advice(config.x, Rotation::cur());
+            let y = meta.query_advice(config.y, Rotation::cur());
+            let swap = meta.query_advice(config.swap, Rotation::cur());
+            let a = meta.query_advice(config.a, Rotation::cur());
+            let b = meta.query_advice(config.b, Rotation::cur());
+            let one = Expression::Constant(F::ONE);
+
+            vec![
+                // swap * (1 - swap) = 0
+                s_swap.clone() * swap.clone() * (one - swap.clone()),
+                // a - (swap * (y - x) + x) = 0
+                s_swap.clone() * (a - (swap.clone() * (y.clone() - x.clone()) + x.clone())),
+                // b - (swap * (x - y) + y) = 0
+                s_swap * (b - (swap * (x - y) + y)),
+            ]
+        });
+
+        config
+    }
+
+    /// Assigns `x`, `y` and `swap` (copied in from existing cells, so the
+    /// caller's `swap` bit is the one actually used here) and returns the
+    /// conditionally-swapped pair `(a, b)`.
+    pub fn cond_swap(
+        &self,
+        mut region: Region<'_, F>,
+        x: AssignedCell<F, F>,
+        y: AssignedCell<F, F>,
+        swap: AssignedCell<F, F>,
+        offset: usize,
+    ) -> Result<(AssignedCell<F, F>, AssignedCell<F, F>), Error> {
+        self.config.s_swap.enable(&mut region, offset)?;
+
+        let x = x.copy_advice(|| "x", &mut region, self.config.x, offset)?;
+        let y = y.copy_advice(|| "y", &mut region, self.config.y, offset)?;
+        let swap = swap.copy_advice(|| "swap", &mut region, self.config.swap, offset)?;
+
+        let a_val = x
+            .value()
+            .zip(y.value())
+            .zip(swap.value())
+            .map(|((x, y), swap)| *swap * (*y - x) + x);
+        let a = region.assign_advice(|| "a", self.config.a, offset, || a_val)?;
+
+        let b_val = x
+            .value()
+            .zip(y.value())
+            .zip(swap.value())
+            .map(|((x, y), swap)| *swap * (*x - y) + y);
+        let b = region.assign_advice(|| "b", self.config.b, offset, || b_val)?;
+
+        Ok((a, b))
+    }
+
+    pub fn copy_cond_swap(
+        &self,
+        mut layouter: impl Layouter<F>,
+        x: AssignedCell<F, F>,
+        y: AssignedCell<F, F>,
+        swap: AssignedCell<F, F>,
+        offset: usize,
+    ) -> Result<(AssignedCell<F, F>, AssignedCell<F, F>), Error> {
+        layouter.assign_region(
+            || "cond_swap",
+            |region: Region<'_, F>| {
+                self.cond_swap(region, x.clone(), y.clone(), swap.clone(), offset)
+            },
+        )
+    }
+}
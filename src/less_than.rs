@@ -1,41 +1,53 @@
 use halo2_proofs::{
-    arithmetic::Field,
     circuit::{AssignedCell, Chip, Layouter, Region, Value},
-    pasta::pallas,
+    pasta::group::ff::{Field, PrimeFieldBits},
     plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector, TableColumn},
     poly::Rotation,
 };
 
+use super::cond_swap::CondSwapChip;
 use super::native_range_check::{NativeRangeCheckChip, NativeRangeCheckConfig};
+use super::utilities::UtilitiesInstructions;
 
 #[derive(Clone, Debug)]
 pub struct LessThanConfig<
+    F: PrimeFieldBits,
     const WINDOW_SIZE: usize,
     const NUM_OF_BITS: usize,
     const NUM_OF_WINDOWS: usize,
 > {
     pub s_lt: Selector,
+    pub s_lt_bool: Selector,
+    pub s_not: Selector,
+    pub s_eq: Selector,
     pub a: Column<Advice>,
     pub b: Column<Advice>,
     pub a_offset: Column<Advice>,
-    pub range_a_config: NativeRangeCheckConfig<WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>,
-    pub range_a_offset_config: NativeRangeCheckConfig<WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>,
+    pub lt: Column<Advice>,
+    pub inv: Column<Advice>,
+    pub range_a_config: NativeRangeCheckConfig<F, WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>,
+    pub range_a_offset_config: NativeRangeCheckConfig<F, WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>,
     pub k_values_table: TableColumn,
 }
 
 #[derive(Clone, Debug)]
 pub struct LessThanChip<
+    F: PrimeFieldBits,
     const WINDOW_SIZE: usize,
     const NUM_OF_BITS: usize,
     const NUM_OF_WINDOWS: usize,
 > {
-    config: LessThanConfig<WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>,
+    config: LessThanConfig<F, WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>,
 }
 
-impl<const WINDOW_SIZE: usize, const NUM_OF_BITS: usize, const NUM_OF_WINDOWS: usize>
-    Chip<pallas::Base> for LessThanChip<WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>
+impl<
+        F: PrimeFieldBits,
+        const WINDOW_SIZE: usize,
+        const NUM_OF_BITS: usize,
+        const NUM_OF_WINDOWS: usize,
+    > Chip<F> for LessThanChip<F, WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>
 {
-    type Config = LessThanConfig<WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>;
+    type Config = LessThanConfig<F, WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>;
     type Loaded = ();
 
     fn config(&self) -> &Self::Config {
@@ -47,50 +59,81 @@ impl<const WINDOW_SIZE: usize, const NUM_OF_BITS: usize, const NUM_OF_WINDOWS: u
     }
 }
 
-impl<const WINDOW_SIZE: usize, const NUM_OF_BITS: usize, const NUM_OF_WINDOWS: usize>
-    LessThanChip<WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>
+impl<
+        F: PrimeFieldBits,
+        const WINDOW_SIZE: usize,
+        const NUM_OF_BITS: usize,
+        const NUM_OF_WINDOWS: usize,
+    > UtilitiesInstructions<F> for LessThanChip<F, WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>
 {
-    pub fn construct(config: LessThanConfig<WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>) -> Self {
+    type Var = AssignedCell<F, F>;
+}
+
+impl<
+        F: PrimeFieldBits,
+        const WINDOW_SIZE: usize,
+        const NUM_OF_BITS: usize,
+        const NUM_OF_WINDOWS: usize,
+    > LessThanChip<F, WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>
+{
+    pub fn construct(config: LessThanConfig<F, WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>) -> Self {
         Self { config }
     }
 
     pub fn configure(
-        meta: &mut ConstraintSystem<pallas::Base>,
+        meta: &mut ConstraintSystem<F>,
         a: Column<Advice>,
         b: Column<Advice>,
         a_offset: Column<Advice>,
+        lt: Column<Advice>,
+        inv: Column<Advice>,
         z1: Column<Advice>,
         z2: Column<Advice>,
         k_values_table: TableColumn,
-    ) -> LessThanConfig<WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS> {
+    ) -> LessThanConfig<F, WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS> {
         let s_lt = meta.selector();
+        let s_lt_bool = meta.selector();
+        let s_not = meta.selector();
+        let s_eq = meta.selector();
 
         meta.enable_equality(a);
         meta.enable_equality(b);
         meta.enable_equality(a_offset);
+        meta.enable_equality(lt);
+        meta.enable_equality(inv);
         meta.enable_equality(z1);
         meta.enable_equality(z2);
 
-        // configure range check for `a` and `offset`
+        // configure range check for `a` and `offset`. Neither of these
+        // instances ever calls `short_range_check`, so the short-range bit
+        // width fixed here is never exercised; `WINDOW_SIZE - 1` is just the
+        // largest value `configure`'s assertion accepts.
         let range_a_config =
-            NativeRangeCheckChip::<WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>::configure(
+            NativeRangeCheckChip::<F, WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>::configure(
                 meta,
                 z1,
                 k_values_table,
+                WINDOW_SIZE - 1,
             );
 
         let range_a_offset_config =
-            NativeRangeCheckChip::<WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>::configure(
+            NativeRangeCheckChip::<F, WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>::configure(
                 meta,
                 z2,
                 k_values_table,
+                WINDOW_SIZE - 1,
             );
 
         let config = LessThanConfig {
             s_lt,
+            s_lt_bool,
+            s_not,
+            s_eq,
             a,
             b,
             a_offset,
+            lt,
+            inv,
             range_a_config,
             range_a_offset_config,
             k_values_table,
@@ -101,49 +144,85 @@ impl<const WINDOW_SIZE: usize, const NUM_OF_BITS: usize, const NUM_OF_WINDOWS: u
             let a = meta.query_advice(config.a, Rotation::cur());
             let b = meta.query_advice(config.b, Rotation::cur());
             let a_offset = meta.query_advice(config.a_offset, Rotation::cur());
-            let two_pow_m =
-                Expression::Constant(pallas::Base::from(2).pow([NUM_OF_BITS as u64, 0, 0, 0]));
+            let two_pow_m = Expression::Constant(F::from(2).pow([NUM_OF_BITS as u64, 0, 0, 0]));
             // a_offset - 2^m + b - a = 0
             vec![s_lt * (a_offset - two_pow_m + b - a)]
         });
 
+        meta.create_gate("lt booleanity and high limb relation", |meta| {
+            let s_lt_bool = meta.query_selector(config.s_lt_bool);
+            let a = meta.query_advice(config.a, Rotation::cur());
+            let b = meta.query_advice(config.b, Rotation::cur());
+            let lt = meta.query_advice(config.lt, Rotation::cur());
+            // `a_offset` doubles as `remainder = a - b + lt * 2^m` here
+            let remainder = meta.query_advice(config.a_offset, Rotation::cur());
+            let two_pow_m = Expression::Constant(F::from(2).pow([NUM_OF_BITS as u64, 0, 0, 0]));
+            let one = Expression::Constant(F::ONE);
+
+            vec![
+                // a - b + lt * 2^m - remainder = 0
+                s_lt_bool.clone() * (a - b + lt.clone() * two_pow_m - remainder),
+                // lt * (1 - lt) = 0
+                s_lt_bool * lt.clone() * (one - lt),
+            ]
+        });
+
+        meta.create_gate("not", |meta| {
+            let s_not = meta.query_selector(config.s_not);
+            // reuses `a_offset` as the boolean input column
+            let input = meta.query_advice(config.a_offset, Rotation::cur());
+            let output = meta.query_advice(config.lt, Rotation::cur());
+            let one = Expression::Constant(F::ONE);
+            // output - (1 - input) = 0
+            vec![s_not * (output - (one - input))]
+        });
+
+        meta.create_gate("is_equal", |meta| {
+            let s_eq = meta.query_selector(config.s_eq);
+            let a = meta.query_advice(config.a, Rotation::cur());
+            let b = meta.query_advice(config.b, Rotation::cur());
+            let inv = meta.query_advice(config.inv, Rotation::cur());
+            // reuses `lt` as the `is_zero` boolean output column
+            let is_zero = meta.query_advice(config.lt, Rotation::cur());
+            let diff = a - b;
+            let one = Expression::Constant(F::ONE);
+
+            vec![
+                // (a - b) * inv - (1 - is_zero) = 0
+                s_eq.clone() * (diff.clone() * inv - (one - is_zero.clone())),
+                // (a - b) * is_zero = 0
+                s_eq * diff * is_zero,
+            ]
+        });
+
         config
     }
 
     pub fn witness_less_than(
         &self,
-        mut layouter: impl Layouter<pallas::Base>,
-        a: Value<pallas::Base>,
-        b: Value<pallas::Base>,
+        mut layouter: impl Layouter<F>,
+        a: Value<F>,
+        b: Value<F>,
         offset: usize,
         strict: bool,
     ) -> Result<(), Error> {
-        let (a, _, a_offset) = layouter.assign_region(
-            || "a less than b",
-            |mut region: Region<'_, pallas::Base>| {
-                let a = region.assign_advice(|| "a", self.config.a, offset, || a)?;
-                let b = region.assign_advice(|| "b", self.config.b, offset, || b)?;
-                let a_offset = self.less_than(region, a.clone(), b.clone(), offset)?;
-                Ok((a, b, a_offset))
-            },
-        )?;
+        let a = self.load_private(layouter.namespace(|| "load a"), self.config.a, a)?;
+        let b = self.load_private(layouter.namespace(|| "load b"), self.config.b, b)?;
 
-        self.less_than_range_check(layouter, a, a_offset, strict)?;
-
-        Ok(())
+        self.copy_less_than(layouter, a, b, offset, strict)
     }
 
     pub fn copy_less_than(
         &self,
-        mut layouter: impl Layouter<pallas::Base>,
-        a: AssignedCell<pallas::Base, pallas::Base>,
-        b: AssignedCell<pallas::Base, pallas::Base>,
+        mut layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
         offset: usize,
         strict: bool,
     ) -> Result<(), Error> {
         let (a, _, a_offset) = layouter.assign_region(
             || "a less than b",
-            |mut region: Region<'_, pallas::Base>| {
+            |mut region: Region<'_, F>| {
                 let a = a.copy_advice(|| "a", &mut region, self.config.a, offset)?;
                 let b = b.copy_advice(|| "b", &mut region, self.config.b, offset)?;
                 let a_offset = self.less_than(region, a.clone(), b.clone(), offset)?;
@@ -158,17 +237,17 @@ impl<const WINDOW_SIZE: usize, const NUM_OF_BITS: usize, const NUM_OF_WINDOWS: u
 
     pub fn less_than_range_check(
         &self,
-        mut layouter: impl Layouter<pallas::Base>,
-        a: AssignedCell<pallas::Base, pallas::Base>,
-        a_offset: AssignedCell<pallas::Base, pallas::Base>,
+        mut layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        a_offset: AssignedCell<F, F>,
         strict: bool,
     ) -> Result<(), Error> {
         let range_a_chip =
-            NativeRangeCheckChip::<WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>::construct(
+            NativeRangeCheckChip::<F, WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>::construct(
                 self.config.range_a_config.clone(),
             );
         let range_a_offset_chip =
-            NativeRangeCheckChip::<WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>::construct(
+            NativeRangeCheckChip::<F, WINDOW_SIZE, NUM_OF_BITS, NUM_OF_WINDOWS>::construct(
                 self.config.range_a_offset_config.clone(),
             );
 
@@ -185,20 +264,351 @@ impl<const WINDOW_SIZE: usize, const NUM_OF_BITS: usize, const NUM_OF_WINDOWS: u
 
     pub fn less_than(
         &self,
-        mut region: Region<'_, pallas::Base>,
-        a: AssignedCell<pallas::Base, pallas::Base>,
-        b: AssignedCell<pallas::Base, pallas::Base>,
+        mut region: Region<'_, F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
         offset: usize,
-    ) -> Result<AssignedCell<pallas::Base, pallas::Base>, Error> {
+    ) -> Result<AssignedCell<F, F>, Error> {
         // enable `less_than` selector
         self.config.s_lt.enable(&mut region, offset)?;
 
         // assign `a + offset`
-        let two_pow_m = pallas::Base::from(2).pow([NUM_OF_BITS as u64, 0, 0, 0]);
+        let two_pow_m = F::from(2).pow([NUM_OF_BITS as u64, 0, 0, 0]);
         let a_offset = a.value().zip(b.value()).map(|(a, b)| *a + (two_pow_m - b));
         let a_offset =
             region.assign_advice(|| "a_offset", self.config.a_offset, offset, || a_offset)?;
 
         Ok(a_offset)
     }
+
+    /// Same relation as [`Self::less_than`], but instead of making the
+    /// circuit unsatisfiable when `a >= b`, it witnesses a boolean `lt` cell
+    /// (`1` when `a < b`, `0` otherwise) that the caller can feed into
+    /// further constraints.
+    ///
+    /// `a + 2^m - b` always lands in `[1, 2^{m+1})` given `a, b < 2^m`, so
+    /// its `m`-th bit is `0` iff `a < b`. We witness that complement
+    /// directly as `lt`, and tie it back to `a`/`b` via
+    /// `remainder = a - b + lt * 2^m`, which is then range-checked to `m`
+    /// bits like the `a_offset` of the assert-only variant.
+    pub fn less_than_bool(
+        &self,
+        mut region: Region<'_, F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+        offset: usize,
+    ) -> Result<(AssignedCell<F, F>, AssignedCell<F, F>), Error> {
+        self.config.s_lt_bool.enable(&mut region, offset)?;
+
+        let two_pow_m = F::from(2).pow([NUM_OF_BITS as u64, 0, 0, 0]);
+
+        let lt = a.value().zip(b.value()).map(|(a, b)| {
+            let a_offset = *a + (two_pow_m - b);
+            if a_offset.to_le_bits()[NUM_OF_BITS] {
+                F::ZERO
+            } else {
+                F::ONE
+            }
+        });
+        let lt = region.assign_advice(|| "lt", self.config.lt, offset, || lt)?;
+
+        let remainder = a
+            .value()
+            .zip(b.value())
+            .zip(lt.value())
+            .map(|((a, b), lt)| *a - b + *lt * two_pow_m);
+        let remainder =
+            region.assign_advice(|| "remainder", self.config.a_offset, offset, || remainder)?;
+
+        Ok((lt, remainder))
+    }
+
+    pub fn witness_less_than_bool(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Value<F>,
+        b: Value<F>,
+        offset: usize,
+        strict: bool,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let a = self.load_private(layouter.namespace(|| "load a"), self.config.a, a)?;
+        let b = self.load_private(layouter.namespace(|| "load b"), self.config.b, b)?;
+
+        self.copy_less_than_bool(layouter, a, b, offset, strict)
+    }
+
+    pub fn copy_less_than_bool(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+        offset: usize,
+        strict: bool,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let (a, lt, remainder) = layouter.assign_region(
+            || "a < b (boolean)",
+            |mut region: Region<'_, F>| {
+                let a = a.copy_advice(|| "a", &mut region, self.config.a, offset)?;
+                let b = b.copy_advice(|| "b", &mut region, self.config.b, offset)?;
+                let (lt, remainder) = self.less_than_bool(region, a.clone(), b, offset)?;
+                Ok((a, lt, remainder))
+            },
+        )?;
+
+        self.less_than_range_check(layouter, a, remainder, strict)?;
+
+        Ok(lt)
+    }
+
+    /// Boolean negation of an already-constrained boolean cell.
+    pub fn not(
+        &self,
+        mut region: Region<'_, F>,
+        input: AssignedCell<F, F>,
+        offset: usize,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        self.config.s_not.enable(&mut region, offset)?;
+
+        let input = input.copy_advice(|| "not input", &mut region, self.config.a_offset, offset)?;
+
+        let output = input.value().map(|v| F::ONE - v);
+        let output = region.assign_advice(|| "not output", self.config.lt, offset, || output)?;
+
+        Ok(output)
+    }
+
+    pub fn copy_not(
+        &self,
+        mut layouter: impl Layouter<F>,
+        input: AssignedCell<F, F>,
+        offset: usize,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        layouter.assign_region(
+            || "not",
+            |region: Region<'_, F>| self.not(region, input.clone(), offset),
+        )
+    }
+
+    /// `is_zero`-style equality check: returns a boolean cell that is `1`
+    /// iff `a == b`, via a witnessed inverse hint `inv` satisfying
+    /// `(a - b)·inv = 1 - is_zero` and `(a - b)·is_zero = 0`.
+    pub fn is_equal(
+        &self,
+        mut region: Region<'_, F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+        offset: usize,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        self.config.s_eq.enable(&mut region, offset)?;
+
+        let diff = a.value().zip(b.value()).map(|(a, b)| *a - b);
+
+        let inv = diff.map(|diff| diff.invert().unwrap_or(F::ZERO));
+        region.assign_advice(|| "inv", self.config.inv, offset, || inv)?;
+
+        let is_zero = diff.map(|diff| {
+            if diff.is_zero_vartime() {
+                F::ONE
+            } else {
+                F::ZERO
+            }
+        });
+        let is_zero = region.assign_advice(|| "is_zero", self.config.lt, offset, || is_zero)?;
+
+        Ok(is_zero)
+    }
+
+    pub fn copy_is_equal(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+        offset: usize,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        layouter.assign_region(
+            || "a == b",
+            |mut region: Region<'_, F>| {
+                let a = a.copy_advice(|| "a", &mut region, self.config.a, offset)?;
+                let b = b.copy_advice(|| "b", &mut region, self.config.b, offset)?;
+                self.is_equal(region, a, b, offset)
+            },
+        )
+    }
+
+    pub fn copy_is_not_equal(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+        offset: usize,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let is_equal =
+            self.copy_is_equal(layouter.namespace(|| "a == b"), a, b, offset)?;
+        self.copy_not(layouter.namespace(|| "!(a == b)"), is_equal, 0)
+    }
+
+    /// `a > b`, asserted by reusing [`Self::copy_less_than`] with the
+    /// operands swapped (`a > b` iff `b < a`).
+    pub fn assert_greater_than(
+        &self,
+        layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+        offset: usize,
+        strict: bool,
+    ) -> Result<(), Error> {
+        self.copy_less_than(layouter, b, a, offset, strict)
+    }
+
+    /// `b < a`, returned as a boolean (`a > b` iff `b < a`).
+    pub fn copy_greater_than_bool(
+        &self,
+        layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+        offset: usize,
+        strict: bool,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        self.copy_less_than_bool(layouter, b, a, offset, strict)
+    }
+
+    /// `a <= b` iff `!(b < a)`.
+    pub fn copy_less_than_or_eq_bool(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+        offset: usize,
+        strict: bool,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let gt = self.copy_greater_than_bool(
+            layouter.namespace(|| "b < a"),
+            a,
+            b,
+            offset,
+            strict,
+        )?;
+        self.copy_not(layouter.namespace(|| "!(b < a)"), gt, 0)
+    }
+
+    /// `a <= b`, asserted.
+    pub fn assert_less_than_or_eq(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+        offset: usize,
+        strict: bool,
+    ) -> Result<(), Error> {
+        let le = self.copy_less_than_or_eq_bool(
+            layouter.namespace(|| "a <= b"),
+            a,
+            b,
+            offset,
+            strict,
+        )?;
+        layouter.assign_region(
+            || "assert a <= b",
+            |mut region: Region<'_, F>| region.constrain_constant(le.cell(), F::ONE),
+        )
+    }
+
+    /// `a >= b` iff `!(a < b)`.
+    pub fn copy_greater_than_or_eq_bool(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+        offset: usize,
+        strict: bool,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let lt = self.copy_less_than_bool(
+            layouter.namespace(|| "a < b"),
+            a,
+            b,
+            offset,
+            strict,
+        )?;
+        self.copy_not(layouter.namespace(|| "!(a < b)"), lt, 0)
+    }
+
+    /// `a >= b`, asserted.
+    pub fn assert_greater_than_or_eq(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+        offset: usize,
+        strict: bool,
+    ) -> Result<(), Error> {
+        let ge = self.copy_greater_than_or_eq_bool(
+            layouter.namespace(|| "a >= b"),
+            a,
+            b,
+            offset,
+            strict,
+        )?;
+        layouter.assign_region(
+            || "assert a >= b",
+            |mut region: Region<'_, F>| region.constrain_constant(ge.cell(), F::ONE),
+        )
+    }
+
+    /// `a == b`, asserted.
+    pub fn assert_equal(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+        offset: usize,
+    ) -> Result<(), Error> {
+        let is_equal = self.copy_is_equal(layouter.namespace(|| "a == b"), a, b, offset)?;
+        layouter.assign_region(
+            || "assert a == b",
+            |mut region: Region<'_, F>| region.constrain_constant(is_equal.cell(), F::ONE),
+        )
+    }
+
+    /// `a != b`, asserted.
+    pub fn assert_not_equal(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+        offset: usize,
+    ) -> Result<(), Error> {
+        let is_equal = self.copy_is_equal(layouter.namespace(|| "a == b"), a, b, offset)?;
+        layouter.assign_region(
+            || "assert a != b",
+            |mut region: Region<'_, F>| region.constrain_constant(is_equal.cell(), F::ZERO),
+        )
+    }
+
+    /// Returns `(min(a, b), max(a, b))`, built from the boolean comparator
+    /// plus a [`CondSwapChip`]. `CondSwapChip::cond_swap` returns
+    /// `(a, b) = if swap {(y, x)} else {(x, y)}`, so feeding it
+    /// `(x, y) = (a, b)` and `swap = a >= b` yields `a` first exactly when
+    /// `a <= b`, i.e. `(min, max)`. Composing this is how callers assemble
+    /// min/max helpers or small sorting networks on top of the boolean
+    /// comparator.
+    pub fn min_max(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cond_swap_chip: &CondSwapChip<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+        offset: usize,
+        strict: bool,
+    ) -> Result<(AssignedCell<F, F>, AssignedCell<F, F>), Error> {
+        let lt = self.copy_less_than_bool(
+            layouter.namespace(|| "a < b"),
+            a.clone(),
+            b.clone(),
+            offset,
+            strict,
+        )?;
+        let ge = self.copy_not(layouter.namespace(|| "!(a < b)"), lt, 0)?;
+
+        cond_swap_chip.copy_cond_swap(layouter.namespace(|| "min/max"), a, b, ge, 0)
+    }
 }
@@ -1,11 +1,17 @@
 use halo2_proofs::arithmetic::Field;
-use halo2_proofs::circuit::{floor_planner::V1, Layouter, Value};
+use halo2_proofs::circuit::{floor_planner::V1, Layouter, Region, Value};
 use halo2_proofs::dev::MockProver;
 use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Error};
 use pasta_curves::{group::ff::PrimeField, Fp};
 
+mod utilities;
+use utilities::UtilitiesInstructions;
+
 mod native_range_check;
-use native_range_check::NativeRangeCheckChip;
+use native_range_check::{NativeRangeCheckChip, NativeRangeCheckConfig};
+
+mod cond_swap;
+use cond_swap::{CondSwapChip, CondSwapConfig};
 
 mod less_than;
 use less_than::{LessThanChip, LessThanConfig};
@@ -14,6 +20,33 @@ const WINDOW_SIZE: usize = 3;
 const NUM_BITS: usize = 253;
 const NUM_WINDOWS: usize = 85;
 
+// Must stay below `WINDOW_SIZE`, which `short_range_check` requires.
+const SHORT_NUM_BITS: usize = 2;
+
+/// Allocates the columns, lookup table, and constants column a
+/// [`LessThanChip`] needs, and configures a [`LessThanConfig`] over them.
+/// Shared by every test circuit below that only needs a `LessThanChip`.
+fn configure_less_than(
+    meta: &mut ConstraintSystem<Fp>,
+) -> LessThanConfig<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS> {
+    let a = meta.advice_column();
+    let b = meta.advice_column();
+    let a_offset = meta.advice_column();
+    let lt = meta.advice_column();
+    let inv = meta.advice_column();
+    let z1 = meta.advice_column();
+    let z2 = meta.advice_column();
+
+    let k_values_table = meta.lookup_table_column();
+
+    let constants = meta.fixed_column();
+    meta.enable_constant(constants);
+
+    LessThanChip::<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>::configure(
+        meta, a, b, a_offset, lt, inv, z1, z2, k_values_table,
+    )
+}
+
 #[derive(Default)]
 struct LessThanCircuit {
     a: Value<Fp>,
@@ -22,7 +55,7 @@ struct LessThanCircuit {
 
 impl Circuit<Fp> for LessThanCircuit {
     type Config = (
-        LessThanConfig<WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>,
+        LessThanConfig<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>,
         Column<Advice>,
     );
     type FloorPlanner = V1;
@@ -38,29 +71,7 @@ impl Circuit<Fp> for LessThanCircuit {
         let w = meta.advice_column();
         meta.enable_equality(w);
 
-        let a = meta.advice_column();
-        let b = meta.advice_column();
-        let a_offset = meta.advice_column();
-        let z1 = meta.advice_column();
-        let z2 = meta.advice_column();
-
-        let k_values_table = meta.lookup_table_column();
-
-        let constants = meta.fixed_column();
-        meta.enable_constant(constants);
-
-        (
-            LessThanChip::<WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>::configure(
-                meta,
-                a,
-                b,
-                a_offset,
-                z1,
-                z2,
-                k_values_table,
-            ),
-            w,
-        )
+        (configure_less_than(meta), w)
     }
 
     fn synthesize(
@@ -69,9 +80,9 @@ impl Circuit<Fp> for LessThanCircuit {
         mut layouter: impl Layouter<Fp>,
     ) -> Result<(), Error> {
         let less_than_chip =
-            LessThanChip::<WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>::construct(config.0.clone());
+            LessThanChip::<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>::construct(config.0.clone());
 
-        NativeRangeCheckChip::<WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>::load_k_table(
+        NativeRangeCheckChip::<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>::load_k_table(
             &mut layouter,
             config.0.k_values_table,
         )?;
@@ -88,6 +99,268 @@ impl Circuit<Fp> for LessThanCircuit {
     }
 }
 
+/// Wires a [`LessThanChip`] and a [`CondSwapChip`] together to compute
+/// `(min(a, b), max(a, b))`, checking the result against `expected_min` /
+/// `expected_max`.
+#[derive(Default, Clone)]
+struct MinMaxCircuit {
+    a: Value<Fp>,
+    b: Value<Fp>,
+    expected_min: Fp,
+    expected_max: Fp,
+}
+
+impl Circuit<Fp> for MinMaxCircuit {
+    type Config = (
+        LessThanConfig<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>,
+        CondSwapConfig,
+    );
+    type FloorPlanner = V1;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            a: Value::unknown(),
+            b: Value::unknown(),
+            expected_min: self.expected_min,
+            expected_max: self.expected_max,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let less_than_config = configure_less_than(meta);
+
+        let x = meta.advice_column();
+        let y = meta.advice_column();
+        let swap = meta.advice_column();
+        let swapped_a = meta.advice_column();
+        let swapped_b = meta.advice_column();
+
+        let cond_swap_config =
+            CondSwapChip::<Fp>::configure(meta, x, y, swap, swapped_a, swapped_b);
+
+        (less_than_config, cond_swap_config)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), Error> {
+        let less_than_chip =
+            LessThanChip::<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>::construct(config.0.clone());
+        let cond_swap_chip = CondSwapChip::<Fp>::construct(config.1.clone());
+
+        NativeRangeCheckChip::<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>::load_k_table(
+            &mut layouter,
+            config.0.k_values_table,
+        )?;
+
+        let a = less_than_chip.load_private(layouter.namespace(|| "load a"), config.0.a, self.a)?;
+        let b = less_than_chip.load_private(layouter.namespace(|| "load b"), config.0.b, self.b)?;
+
+        let (min, max) = less_than_chip.min_max(
+            layouter.namespace(|| "min/max"),
+            &cond_swap_chip,
+            a,
+            b,
+            0,
+            true,
+        )?;
+
+        layouter.assign_region(
+            || "check min/max",
+            |mut region: Region<'_, Fp>| {
+                region.constrain_constant(min.cell(), self.expected_min)?;
+                region.constrain_constant(max.cell(), self.expected_max)
+            },
+        )
+    }
+}
+
+/// Exercises [`NativeRangeCheckChip::short_range_check`] directly, checking
+/// that `value` fits in `SHORT_NUM_BITS` bits.
+#[derive(Default, Clone)]
+struct ShortRangeCheckCircuit {
+    value: Value<Fp>,
+}
+
+impl Circuit<Fp> for ShortRangeCheckCircuit {
+    type Config = NativeRangeCheckConfig<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>;
+    type FloorPlanner = V1;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            value: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let z = meta.advice_column();
+        let k_values_table = meta.lookup_table_column();
+
+        NativeRangeCheckChip::<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>::configure(
+            meta,
+            z,
+            k_values_table,
+            SHORT_NUM_BITS,
+        )
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), Error> {
+        let chip =
+            NativeRangeCheckChip::<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>::construct(
+                config.clone(),
+            );
+
+        NativeRangeCheckChip::<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>::load_k_table(
+            &mut layouter,
+            config.k_values_table,
+        )?;
+
+        layouter.assign_region(
+            || "short range check",
+            |mut region: Region<'_, Fp>| {
+                chip.short_range_check(&mut region, self.value, 0)?;
+                Ok(())
+            },
+        )
+    }
+}
+
+/// Exercises [`LessThanChip::witness_less_than_bool`], checking the
+/// witnessed `lt` boolean against `expected_lt`.
+#[derive(Default, Clone)]
+struct LessThanBoolCircuit {
+    a: Value<Fp>,
+    b: Value<Fp>,
+    expected_lt: Fp,
+}
+
+impl Circuit<Fp> for LessThanBoolCircuit {
+    type Config = LessThanConfig<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>;
+    type FloorPlanner = V1;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            a: Value::unknown(),
+            b: Value::unknown(),
+            expected_lt: self.expected_lt,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        configure_less_than(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), Error> {
+        let chip = LessThanChip::<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>::construct(config.clone());
+
+        NativeRangeCheckChip::<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>::load_k_table(
+            &mut layouter,
+            config.k_values_table,
+        )?;
+
+        let lt = chip.witness_less_than_bool(
+            layouter.namespace(|| "a < b (bool)"),
+            self.a,
+            self.b,
+            0,
+            true,
+        )?;
+
+        layouter.assign_region(
+            || "check lt",
+            |mut region: Region<'_, Fp>| region.constrain_constant(lt.cell(), self.expected_lt),
+        )
+    }
+}
+
+/// Which comparator [`CompareCircuit`] should assert between `a` and `b`.
+#[derive(Clone, Copy, Debug)]
+enum CompareOp {
+    GreaterThan,
+    LessThanOrEq,
+    GreaterThanOrEq,
+    Equal,
+    NotEqual,
+}
+
+/// Exercises the assert-style comparator family
+/// (`assert_greater_than`/`assert_less_than_or_eq`/
+/// `assert_greater_than_or_eq`/`assert_equal`/`assert_not_equal`, which in
+/// turn exercise `is_equal` and `not`) on `a` and `b`.
+#[derive(Clone)]
+struct CompareCircuit {
+    a: Value<Fp>,
+    b: Value<Fp>,
+    op: CompareOp,
+}
+
+impl Default for CompareCircuit {
+    fn default() -> Self {
+        Self {
+            a: Value::unknown(),
+            b: Value::unknown(),
+            op: CompareOp::Equal,
+        }
+    }
+}
+
+impl Circuit<Fp> for CompareCircuit {
+    type Config = LessThanConfig<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>;
+    type FloorPlanner = V1;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            a: Value::unknown(),
+            b: Value::unknown(),
+            op: self.op,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        configure_less_than(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), Error> {
+        let chip = LessThanChip::<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>::construct(config.clone());
+
+        NativeRangeCheckChip::<Fp, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>::load_k_table(
+            &mut layouter,
+            config.k_values_table,
+        )?;
+
+        let a = chip.load_private(layouter.namespace(|| "load a"), config.a, self.a)?;
+        let b = chip.load_private(layouter.namespace(|| "load b"), config.b, self.b)?;
+
+        match self.op {
+            CompareOp::GreaterThan => {
+                chip.assert_greater_than(layouter.namespace(|| "a > b"), a, b, 0, true)
+            }
+            CompareOp::LessThanOrEq => {
+                chip.assert_less_than_or_eq(layouter.namespace(|| "a <= b"), a, b, 0, true)
+            }
+            CompareOp::GreaterThanOrEq => {
+                chip.assert_greater_than_or_eq(layouter.namespace(|| "a >= b"), a, b, 0, true)
+            }
+            CompareOp::Equal => chip.assert_equal(layouter.namespace(|| "a == b"), a, b, 0),
+            CompareOp::NotEqual => chip.assert_not_equal(layouter.namespace(|| "a != b"), a, b, 0),
+        }
+    }
+}
+
 #[allow(non_snake_case)]
 fn main() {
     let P_MINUS_1 = Fp::ZERO - Fp::ONE;
@@ -148,4 +421,127 @@ fn main() {
         let prover = MockProver::run(7, &circuit, vec![]).unwrap();
         assert!(prover.verify().is_err());
     }
+
+    println!("");
+
+    // min/max, via CondSwapChip wired into LessThanChip::min_max
+    let min_max_pairs: [(u64, u64); 4] = [(0, 1), (1, 0), (0, 0), (u64::MAX - 1, u64::MAX)];
+
+    for (a_int, b_int) in min_max_pairs {
+        let a = Fp::from(a_int);
+        let b = Fp::from(b_int);
+        let (expected_min, expected_max) = if a_int <= b_int { (a, b) } else { (b, a) };
+        let circuit = MinMaxCircuit {
+            a: Value::known(a),
+            b: Value::known(b),
+            expected_min,
+            expected_max,
+        };
+        println!("[SHOULD PASS] min/max({:?}, {:?})", a, b);
+        let prover = MockProver::run(7, &circuit, vec![]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    // A wrong (swapped) expectation must fail.
+    let (a, b) = (Fp::ZERO, Fp::ONE);
+    let circuit = MinMaxCircuit {
+        a: Value::known(a),
+        b: Value::known(b),
+        expected_min: b,
+        expected_max: a,
+    };
+    println!(
+        "[SHOULD FAIL] min/max({:?}, {:?}) with swapped expectation",
+        a, b
+    );
+    let prover = MockProver::run(7, &circuit, vec![]).unwrap();
+    assert!(prover.verify().is_err());
+
+    println!("");
+
+    // short_range_check(value, SHORT_NUM_BITS)
+    for v in 0u64..(1 << SHORT_NUM_BITS) {
+        let circuit = ShortRangeCheckCircuit {
+            value: Value::known(Fp::from(v)),
+        };
+        println!("[SHOULD PASS] {} fits in {} bits", v, SHORT_NUM_BITS);
+        let prover = MockProver::run(7, &circuit, vec![]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    for v in (1 << SHORT_NUM_BITS)..(1 << WINDOW_SIZE) {
+        let circuit = ShortRangeCheckCircuit {
+            value: Value::known(Fp::from(v)),
+        };
+        println!("[SHOULD FAIL] {} does not fit in {} bits", v, SHORT_NUM_BITS);
+        let prover = MockProver::run(7, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    println!("");
+
+    // witness_less_than_bool(a, b) == expect_lt
+    let less_than_bool_cases = [
+        (Fp::ZERO, Fp::ONE, true),
+        (Fp::ONE, Fp::ZERO, false),
+        (Fp::ZERO, Fp::ZERO, false),
+        (MAX_253 - Fp::ONE, MAX_253, true),
+    ];
+
+    for (a, b, expect_lt) in less_than_bool_cases {
+        let circuit = LessThanBoolCircuit {
+            a: Value::known(a),
+            b: Value::known(b),
+            expected_lt: if expect_lt { Fp::ONE } else { Fp::ZERO },
+        };
+        println!("[SHOULD PASS] ({:?} < {:?}) == {}", a, b, expect_lt);
+        let prover = MockProver::run(7, &circuit, vec![]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    for (a, b, expect_lt) in less_than_bool_cases {
+        let circuit = LessThanBoolCircuit {
+            a: Value::known(a),
+            b: Value::known(b),
+            expected_lt: if expect_lt { Fp::ZERO } else { Fp::ONE },
+        };
+        println!("[SHOULD FAIL] ({:?} < {:?}) != {}", a, b, !expect_lt);
+        let prover = MockProver::run(7, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    println!("");
+
+    // assert_greater_than / assert_less_than_or_eq / assert_greater_than_or_eq
+    // / assert_equal / assert_not_equal (which also exercise is_equal and not)
+    let compare_cases = [
+        (Fp::ONE, Fp::ZERO, CompareOp::GreaterThan, true),
+        (Fp::ZERO, Fp::ONE, CompareOp::GreaterThan, false),
+        (Fp::ZERO, Fp::ONE, CompareOp::LessThanOrEq, true),
+        (Fp::ONE, Fp::ONE, CompareOp::LessThanOrEq, true),
+        (Fp::ONE, Fp::ZERO, CompareOp::LessThanOrEq, false),
+        (Fp::ONE, Fp::ZERO, CompareOp::GreaterThanOrEq, true),
+        (Fp::ONE, Fp::ONE, CompareOp::GreaterThanOrEq, true),
+        (Fp::ZERO, Fp::ONE, CompareOp::GreaterThanOrEq, false),
+        (Fp::ONE, Fp::ONE, CompareOp::Equal, true),
+        (Fp::ONE, Fp::ZERO, CompareOp::Equal, false),
+        (Fp::ONE, Fp::ZERO, CompareOp::NotEqual, true),
+        (Fp::ONE, Fp::ONE, CompareOp::NotEqual, false),
+    ];
+
+    for (a, b, op, should_pass) in compare_cases {
+        let circuit = CompareCircuit {
+            a: Value::known(a),
+            b: Value::known(b),
+            op,
+        };
+        let prover = MockProver::run(7, &circuit, vec![]).unwrap();
+        if should_pass {
+            println!("[SHOULD PASS] {:?} {:?} {:?}", a, op, b);
+            prover.assert_satisfied();
+        } else {
+            println!("[SHOULD FAIL] {:?} {:?} {:?}", a, op, b);
+            assert!(prover.verify().is_err());
+        }
+    }
 }
@@ -1,38 +1,73 @@
+use std::marker::PhantomData;
+
 use halo2_proofs::{
     circuit::{AssignedCell, Chip, Layouter, Region, Value},
-    pasta::{
-        group::ff::{Field, PrimeFieldBits},
-        pallas,
-    },
+    pasta::group::ff::{Field, PrimeFieldBits},
     plonk,
     plonk::{Advice, Column, ConstraintSystem, Selector, TableColumn},
     poly::Rotation,
 };
 
+use super::utilities::UtilitiesInstructions;
+
+/// The running sum `[z_0, z_1, ..., z_{NUM_WINDOWS}]` produced by
+/// [`NativeRangeCheckChip::decompose`], where `z_0` is the original value
+/// and each subsequent `z_i` is the value with its low `i * WINDOW_SIZE`
+/// bits stripped off.
+///
+/// `WINDOW_SIZE` is carried on the type itself (rather than on
+/// [`Self::window`]) so that it's tied to the [`NativeRangeCheckChip`] that
+/// produced this running sum: a caller can't turbofish a mismatched window
+/// size and silently get back the wrong limb.
+#[derive(Clone, Debug)]
+pub struct RunningSum<F: PrimeFieldBits, const WINDOW_SIZE: usize>(Vec<AssignedCell<F, F>>);
+
+impl<F: PrimeFieldBits, const WINDOW_SIZE: usize> RunningSum<F, WINDOW_SIZE> {
+    /// Returns the `i`-th partial sum `z_i`.
+    pub fn z(&self, i: usize) -> &AssignedCell<F, F> {
+        &self.0[i]
+    }
+
+    /// Returns all the `z_i` cells, in order.
+    pub fn as_slice(&self) -> &[AssignedCell<F, F>] {
+        &self.0
+    }
+
+    /// Returns the `WINDOW_SIZE`-bit limb `k_i = z_i - 2^K * z_{i+1}`.
+    pub fn window(&self, i: usize) -> Value<F> {
+        let two_pow_k = F::from(1 << WINDOW_SIZE as u64);
+        self.0[i].value().copied() - self.0[i + 1].value().copied() * Value::known(two_pow_k)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct NativeRangeCheckConfig<
+    F: PrimeFieldBits,
     const WINDOW_SIZE: usize,
     const NUM_BITS: usize,
     const NUM_WINDOWS: usize,
 > {
     pub z: Column<Advice>,
     pub s_rc: Selector,
+    pub s_short: Selector,
     pub k_values_table: TableColumn,
+    _marker: PhantomData<F>,
 }
 
 #[derive(Clone, Debug)]
 pub struct NativeRangeCheckChip<
+    F: PrimeFieldBits,
     const WINDOW_SIZE: usize,
     const NUM_BITS: usize,
     const NUM_WINDOWS: usize,
 > {
-    config: NativeRangeCheckConfig<WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>,
+    config: NativeRangeCheckConfig<F, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>,
 }
 
-impl<const WINDOW_SIZE: usize, const NUM_BITS: usize, const NUM_WINDOWS: usize> Chip<pallas::Base>
-    for NativeRangeCheckChip<WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>
+impl<F: PrimeFieldBits, const WINDOW_SIZE: usize, const NUM_BITS: usize, const NUM_WINDOWS: usize>
+    Chip<F> for NativeRangeCheckChip<F, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>
 {
-    type Config = NativeRangeCheckConfig<WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>;
+    type Config = NativeRangeCheckConfig<F, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>;
     type Loaded = ();
 
     fn config(&self) -> &Self::Config {
@@ -44,18 +79,29 @@ impl<const WINDOW_SIZE: usize, const NUM_BITS: usize, const NUM_WINDOWS: usize>
     }
 }
 
-impl<const WINDOW_SIZE: usize, const NUM_BITS: usize, const NUM_WINDOWS: usize>
-    NativeRangeCheckChip<WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>
+impl<F: PrimeFieldBits, const WINDOW_SIZE: usize, const NUM_BITS: usize, const NUM_WINDOWS: usize>
+    UtilitiesInstructions<F> for NativeRangeCheckChip<F, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>
+{
+    type Var = AssignedCell<F, F>;
+}
+
+impl<F: PrimeFieldBits, const WINDOW_SIZE: usize, const NUM_BITS: usize, const NUM_WINDOWS: usize>
+    NativeRangeCheckChip<F, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>
 {
-    pub fn construct(config: NativeRangeCheckConfig<WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>) -> Self {
+    pub fn construct(
+        config: NativeRangeCheckConfig<F, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS>,
+    ) -> Self {
         Self { config }
     }
 
     pub fn configure(
-        meta: &mut ConstraintSystem<pallas::Base>,
+        meta: &mut ConstraintSystem<F>,
         z: Column<Advice>,
         k_values_table: TableColumn,
-    ) -> NativeRangeCheckConfig<WINDOW_SIZE, NUM_BITS, NUM_WINDOWS> {
+        short_num_bits: usize,
+    ) -> NativeRangeCheckConfig<F, WINDOW_SIZE, NUM_BITS, NUM_WINDOWS> {
+        assert!(short_num_bits < WINDOW_SIZE);
+
         // Enable permutation on z column
         meta.enable_equality(z);
 
@@ -69,22 +115,49 @@ impl<const WINDOW_SIZE: usize, const NUM_BITS: usize, const NUM_WINDOWS: usize>
             //    z_next = (z_curr - k_i) / 2^K
             // => k_i = z_curr - (z_next * 2^K)
             vec![(
-                s_rc * (z_curr - z_next * pallas::Base::from(1 << WINDOW_SIZE)),
+                s_rc * (z_curr - z_next * F::from(1 << WINDOW_SIZE)),
                 k_values_table,
             )]
         });
 
+        let s_short = meta.complex_selector();
+        let shift = F::from(1 << (WINDOW_SIZE - short_num_bits) as u64);
+
+        // Constrain `element` (at the current row) to be in the K-bit table.
+        meta.lookup(|meta| {
+            let s_short = meta.query_selector(s_short);
+            let element = meta.query_advice(z, Rotation::cur());
+            vec![(s_short * element, k_values_table)]
+        });
+
+        // Constrain `element * 2^(K - short_num_bits)` -- derived from that
+        // *same* queried cell, not a second independently-witnessed one --
+        // to also land in the K-bit table, which (given the lookup above)
+        // can only hold if `element < 2^short_num_bits`. Folding the
+        // multiplication into the lookup expression itself (rather than
+        // witnessing `shifted` in its own cell and looking *that* up) is
+        // what ties it to `element`: a second cell would let a prover who
+        // doesn't go through `short_range_check`'s honest witness assignment
+        // supply any in-table value there, regardless of `element`.
+        meta.lookup(|meta| {
+            let s_short = meta.query_selector(s_short);
+            let element = meta.query_advice(z, Rotation::cur());
+            vec![(s_short * element * shift, k_values_table)]
+        });
+
         NativeRangeCheckConfig {
             z,
             s_rc,
+            s_short,
             k_values_table,
+            _marker: PhantomData,
         }
     }
 
     /// `k_values_table` should be reused across different chips
     /// which is why we don't limit it to a specific instance.
     pub fn load_k_table(
-        layouter: &mut impl Layouter<pallas::Base>,
+        layouter: &mut impl Layouter<F>,
         k_values_table: TableColumn,
     ) -> Result<(), plonk::Error> {
         layouter.assign_table(
@@ -95,7 +168,7 @@ impl<const WINDOW_SIZE: usize, const NUM_BITS: usize, const NUM_WINDOWS: usize>
                         || format!("{} window assign", WINDOW_SIZE),
                         k_values_table,
                         index,
-                        || Value::known(pallas::Base::from(index as u64)),
+                        || Value::known(F::from(index as u64)),
                     )?;
                 }
                 Ok(())
@@ -103,7 +176,7 @@ impl<const WINDOW_SIZE: usize, const NUM_BITS: usize, const NUM_WINDOWS: usize>
         )
     }
 
-    fn decompose_value(value: &pallas::Base) -> Vec<[bool; WINDOW_SIZE]> {
+    fn decompose_value(value: &F) -> Vec<[bool; WINDOW_SIZE]> {
         let padding = (WINDOW_SIZE - NUM_BITS % WINDOW_SIZE) % WINDOW_SIZE;
 
         let bits: Vec<bool> = value
@@ -125,11 +198,11 @@ impl<const WINDOW_SIZE: usize, const NUM_BITS: usize, const NUM_WINDOWS: usize>
 
     pub fn decompose(
         &self,
-        region: &mut Region<'_, pallas::Base>,
-        z_0: AssignedCell<pallas::Base, pallas::Base>,
+        region: &mut Region<'_, F>,
+        z_0: AssignedCell<F, F>,
         offset: usize,
         strict: bool,
-    ) -> Result<(), plonk::Error> {
+    ) -> Result<RunningSum<F, WINDOW_SIZE>, plonk::Error> {
         assert!(WINDOW_SIZE * NUM_WINDOWS < NUM_BITS + WINDOW_SIZE);
 
         // Enable selectors
@@ -137,25 +210,20 @@ impl<const WINDOW_SIZE: usize, const NUM_BITS: usize, const NUM_WINDOWS: usize>
             self.config.s_rc.enable(region, index + offset)?;
         }
 
-        let mut z_values: Vec<AssignedCell<pallas::Base, pallas::Base>> = vec![z_0.clone()];
+        let mut z_values: Vec<AssignedCell<F, F>> = vec![z_0.clone()];
         let mut z = z_0;
         let decomposed_chunks = z
             .value()
             .map(Self::decompose_value)
             .transpose_vec(NUM_WINDOWS);
 
-        let two_pow_k_inverse = Value::known(
-            pallas::Base::from(1 << WINDOW_SIZE as u64)
-                .invert()
-                .unwrap(),
-        );
+        let two_pow_k_inverse = Value::known(F::from(1 << WINDOW_SIZE as u64).invert().unwrap());
 
         for (i, chunk) in decomposed_chunks.iter().enumerate() {
             let z_next = {
                 let z_curr = z.value().copied();
-                let chunk_value = chunk.map(|c| {
-                    pallas::Base::from(c.iter().rev().fold(0, |acc, c| (acc << 1) + *c as u64))
-                });
+                let chunk_value = chunk
+                    .map(|c| F::from(c.iter().rev().fold(0, |acc, c| (acc << 1) + *c as u64)));
                 // z_next = (z_curr - k_i) / 2^K
                 let z_next = (z_curr - chunk_value) * two_pow_k_inverse;
                 region.assign_advice(
@@ -173,41 +241,54 @@ impl<const WINDOW_SIZE: usize, const NUM_BITS: usize, const NUM_WINDOWS: usize>
 
         if strict {
             // Constrain the remaining bits to be zero
-            region.constrain_constant(z_values.last().unwrap().cell(), pallas::Base::zero())?;
+            region.constrain_constant(z_values.last().unwrap().cell(), F::ZERO)?;
         }
 
-        Ok(())
+        Ok(RunningSum(z_values))
     }
 
     pub fn witness_range_check(
         &self,
-        mut layouter: impl Layouter<pallas::Base>,
-        value: Value<pallas::Base>,
+        mut layouter: impl Layouter<F>,
+        value: Value<F>,
         strict: bool,
     ) -> Result<(), plonk::Error> {
-        layouter.assign_region(
-            || format!("witness {}-bit native range check", NUM_BITS),
-            |mut region: Region<'_, pallas::Base>| {
-                let z_0 = region.assign_advice(|| "z_0", self.config.z, 0, || value)?;
-                self.decompose(&mut region, z_0, 0, strict)?;
-                Ok(())
-            },
-        )
+        let z_0 = self.load_private(layouter.namespace(|| "load value"), self.config.z, value)?;
+        self.copy_range_check(layouter, z_0, strict)
     }
 
     pub fn copy_range_check(
         &self,
-        mut layouter: impl Layouter<pallas::Base>,
-        value: AssignedCell<pallas::Base, pallas::Base>,
+        mut layouter: impl Layouter<F>,
+        value: AssignedCell<F, F>,
         strict: bool,
     ) -> Result<(), plonk::Error> {
         layouter.assign_region(
             || format!("copy {}-bit native range check", NUM_BITS),
-            |mut region: Region<'_, pallas::Base>| {
+            |mut region: Region<'_, F>| {
                 let z_0 = value.copy_advice(|| "z_0", &mut region, self.config.z, 0)?;
                 self.decompose(&mut region, z_0, 0, strict)?;
                 Ok(())
             },
         )
     }
+
+    /// Checks that `element` fits in the `short_num_bits < WINDOW_SIZE` bits
+    /// fixed at [`Self::configure`], using a single pair of lookups instead
+    /// of a full windowed decomposition. Both lookups query the same
+    /// assigned `element` cell -- one constrains `element` itself to the
+    /// `WINDOW_SIZE`-bit table (so `element < 2^WINDOW_SIZE`), the other
+    /// constrains `element * 2^(WINDOW_SIZE - short_num_bits)` the same way,
+    /// which (the shift being too small to wrap the field) can only hold if
+    /// `element < 2^short_num_bits`.
+    pub fn short_range_check(
+        &self,
+        region: &mut Region<'_, F>,
+        element: Value<F>,
+        offset: usize,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        self.config.s_short.enable(region, offset)?;
+
+        region.assign_advice(|| "short range check element", self.config.z, offset, || element)
+    }
 }
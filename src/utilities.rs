@@ -0,0 +1,49 @@
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Region, Value},
+    pasta::group::ff::PrimeFieldBits,
+    plonk::{Advice, Column, Error},
+};
+
+/// An assigned variable in the circuit.
+///
+/// This mirrors the `Var` abstraction used across halo2 gadget libraries,
+/// so chips in this crate can be wired up next to other chips that already
+/// speak the same trait instead of trafficking in raw `AssignedCell`s.
+pub trait Var<F: PrimeFieldBits>: Clone + std::fmt::Debug + From<AssignedCell<F, F>> {
+    fn value(&self) -> Value<F>;
+    fn cell(&self) -> AssignedCell<F, F>;
+}
+
+impl<F: PrimeFieldBits> Var<F> for AssignedCell<F, F> {
+    fn value(&self) -> Value<F> {
+        self.value().copied()
+    }
+
+    fn cell(&self) -> AssignedCell<F, F> {
+        self.clone()
+    }
+}
+
+/// Instructions to load a private value into the circuit.
+pub trait UtilitiesInstructions<F: PrimeFieldBits> {
+    /// A variable in the circuit.
+    type Var: Var<F>;
+
+    /// Witnesses a private value in the given column at offset 0 of a
+    /// fresh region.
+    fn load_private(
+        &self,
+        mut layouter: impl Layouter<F>,
+        column: Column<Advice>,
+        value: Value<F>,
+    ) -> Result<Self::Var, Error> {
+        layouter.assign_region(
+            || "load private",
+            |mut region: Region<'_, F>| {
+                region
+                    .assign_advice(|| "load private", column, 0, || value)
+                    .map(Self::Var::from)
+            },
+        )
+    }
+}